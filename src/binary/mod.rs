@@ -1,5 +1,9 @@
 mod view;
-pub use self::view::{BorrowView, View};
+mod view_ext;
+mod view_reader;
+pub use self::view::{AnchoredView, BorrowView, FileView, InvalidPointer, View};
+pub use self::view_ext::{FrameTooLarge, VarintOutOfRange, VarintTooLong, ViewExt};
+pub use self::view_reader::{UnboundedView, ViewReader};
 use crate::imports::*;
 
 pub type Result<T> = std::result::Result<T, ReadError>;
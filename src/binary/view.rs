@@ -3,6 +3,8 @@ use crate::imports::*;
 use smallvec::SmallVec;
 use std::cmp::min;
 use std::convert::TryFrom;
+use std::fs::File;
+use std::os::unix::fs::FileExt;
 
 /// A view on immutable binary data.
 ///
@@ -100,6 +102,11 @@ impl<T: Clone + Borrow<[u8]>> View for BorrowView<T> {
             .ok_or(EndOfStream)
     }
     fn transcribe(&self, len: usize) -> Result<SmallVecU8> {
+        if let Some(bound) = self.bound_offset {
+            if self.offset.saturating_add(len) > bound {
+                return Err(EndOfStream);
+            }
+        }
         let slice = self
             .handle
             .borrow()
@@ -137,14 +144,261 @@ impl<T: Clone + Borrow<[u8]>> View for BorrowView<T> {
     }
 
     fn bound_len(&self) -> Option<usize> {
-        Some(self.handle.borrow().len().saturating_sub(self.offset))
+        let remaining = self.handle.borrow().len().saturating_sub(self.offset);
+        Some(match self.bound_offset {
+            Some(bound) => min(remaining, bound.saturating_sub(self.offset)),
+            None => remaining,
+        })
     }
     fn hint_available_bytes(&self) -> Option<usize> {
-        Some(self.handle.borrow().len().saturating_sub(self.offset))
+        self.bound_len()
+    }
+}
+
+/// A view over a file, backed by positioned reads (`pread`) rather than an in-memory
+/// buffer. Unlike [`BorrowView`], this does not require the whole file to fit in memory:
+/// `T: Clone + Borrow<File>` means cheap `Clone`s (e.g. an `Rc<File>`) can each hold their
+/// own independent `offset`/`bound_offset`, all reading from the same underlying handle.
+#[derive(Debug, Clone)]
+pub struct FileView<T: Clone + Borrow<File>> {
+    handle: T,
+    offset: u64,
+    bound_offset: Option<u64>, // Offset of first byte that is not allowed to be read
+}
+
+impl<T: Clone + Borrow<File>> FileView<T> {
+    pub fn new(handle: T) -> Self {
+        Self::new_offset(handle, 0)
+    }
+
+    pub fn new_offset(handle: T, offset: u64) -> Self {
+        FileView {
+            handle,
+            offset,
+            bound_offset: None,
+        }
+    }
+}
+
+/// Read exactly `buf.len()` bytes from `file` at `offset`, looping over short reads.
+/// Returns `EndOfStream` if the file ends before `buf` is filled.
+fn read_exact_at(file: &File, buf: &mut [u8], offset: u64) -> Result<()> {
+    let mut total = 0;
+    while total < buf.len() {
+        let n = file
+            .read_at(&mut buf[total..], offset + total as u64)
+            .map_err(|_| EndOfStream)?;
+        if n == 0 {
+            return Err(EndOfStream);
+        }
+        total += n;
+    }
+    Ok(())
+}
+
+impl<T: Clone + Borrow<File>> View for FileView<T> {
+    fn read_byte(&self) -> Result<u8> {
+        if let Some(bound) = self.bound_offset {
+            if self.offset >= bound {
+                return Err(EndOfStream);
+            }
+        }
+        let mut buf = [0u8; 1];
+        read_exact_at(self.handle.borrow(), &mut buf, self.offset)?;
+        Ok(buf[0])
+    }
+    fn transcribe(&self, len: usize) -> Result<SmallVecU8> {
+        let end = self.offset.saturating_add(len as u64);
+        if let Some(bound) = self.bound_offset {
+            if end > bound {
+                return Err(EndOfStream);
+            }
+        }
+        // Check against the real file size before allocating: an unbounded view (no
+        // `bound_offset`) would otherwise let a large `len` (e.g. a malformed length-prefixed
+        // frame header) allocate far more than the file could ever supply.
+        let file_len = self.handle.borrow().metadata().map_err(|_| EndOfStream)?.len();
+        if end > file_len {
+            return Err(EndOfStream);
+        }
+        let mut buf = vec![0u8; len];
+        read_exact_at(self.handle.borrow(), &mut buf, self.offset)?;
+        Ok(SmallVec::from_vec(buf))
+    }
+    fn skip(self, bytes: u64) -> Result<Self> {
+        let new_offset = self.offset.saturating_add(bytes);
+        if let Some(bound) = self.bound_offset {
+            if new_offset > bound {
+                return Err(EndOfStream);
+            }
+        }
+        Ok(FileView {
+            handle: self.handle,
+            offset: new_offset,
+            bound_offset: self.bound_offset,
+        })
+    }
+
+    fn bound(mut self, len: u64) -> Self {
+        let suggested_bound_offset = self.offset.saturating_add(len);
+        self.bound_offset = Some(match self.bound_offset {
+            None => suggested_bound_offset,
+            Some(cur) => min(cur, suggested_bound_offset),
+        });
+        self
+    }
+
+    fn bound_len(&self) -> Option<usize> {
+        let bound = self.bound_offset?;
+        usize::try_from(bound.saturating_sub(self.offset)).ok()
+    }
+
+    fn hint_available_bytes(&self) -> Option<usize> {
+        let file_len = self.handle.borrow().metadata().ok()?.len();
+        let available = file_len.saturating_sub(self.offset);
+        let available = match self.bound_offset {
+            Some(bound) => min(available, bound.saturating_sub(self.offset)),
+            None => available,
+        };
+        usize::try_from(available).ok()
+    }
+}
+
+/// A dedicated error for a backward pointer that is malformed or could cause an infinite
+/// loop: one that does not point strictly before the current position, or that points
+/// outside the underlying buffer.
+#[derive(Debug)]
+pub struct InvalidPointer;
+
+impl fmt::Display for InvalidPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pointer does not point strictly backward within the buffer")
+    }
+}
+
+impl Error for InvalidPointer {}
+impl super::ViewReadError for InvalidPointer {}
+
+/// Views that hold their entire underlying buffer in memory can support random access,
+/// unlike the forward-only `View` contract. This lets formats with backward offset
+/// pointers — e.g. DNS message compression — jump to an earlier position to resolve a
+/// reference, and then continue reading from where they left off using the original view.
+pub trait AnchoredView: View {
+    /// The offset of this view from the start of the underlying buffer.
+    fn position(&self) -> u64;
+
+    /// Create a fresh, unbounded view at `absolute_offset` bytes from the start of the
+    /// underlying buffer.
+    ///
+    /// `absolute_offset` must point strictly before [`position`](Self::position), so that
+    /// repeatedly following pointers cannot loop forever, and must be within the buffer.
+    /// Violating either returns [`InvalidPointer`] via `ReadError::Other`.
+    fn reanchor(&self, absolute_offset: u64) -> Result<Self>;
+}
+
+impl<T: Clone + Borrow<[u8]>> AnchoredView for BorrowView<T> {
+    fn position(&self) -> u64 {
+        self.offset as u64
+    }
+
+    fn reanchor(&self, absolute_offset: u64) -> Result<Self> {
+        if absolute_offset >= self.position() {
+            return Err(super::ReadError::Other(Box::new(InvalidPointer)));
+        }
+        let offset = usize::try_from(absolute_offset)
+            .map_err(|_| super::ReadError::Other(Box::new(InvalidPointer)))?;
+        if offset > self.handle.borrow().len() {
+            return Err(super::ReadError::Other(Box::new(InvalidPointer)));
+        }
+        Ok(BorrowView {
+            handle: self.handle.clone(),
+            offset,
+            bound_offset: None,
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
-    // todo
+    use super::*;
+    use super::super::ReadError;
+    use std::io::Write;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn borrow_view_transcribe_and_skip() {
+        let view = BorrowView::new(vec![1u8, 2, 3, 4, 5]);
+        assert_eq!(view.read_byte().unwrap(), 1);
+        assert_eq!(view.transcribe(3).unwrap().as_slice(), &[1, 2, 3]);
+        let view = view.skip(3).unwrap();
+        assert_eq!(view.read_byte().unwrap(), 4);
+        assert_eq!(view.transcribe(3), Err(EndOfStream));
+    }
+
+    #[test]
+    fn borrow_view_bound_rejects_reads_past_it() {
+        let view = BorrowView::new(vec![1u8, 2, 3, 4, 5]).bound(2);
+        assert_eq!(view.transcribe(2).unwrap().as_slice(), &[1, 2]);
+        assert!(matches!(view.clone().skip(3), Err(EndOfStream)));
+        assert_eq!(view.bound_len(), Some(2));
+    }
+
+    #[test]
+    fn borrow_view_anchored_reanchor_rejects_forward_and_out_of_range_pointers() {
+        let view = BorrowView::new(vec![1u8, 2, 3, 4, 5]).skip(3).unwrap();
+        assert_eq!(view.position(), 3);
+
+        // Pointing strictly backward, within the buffer, is fine.
+        let reanchored = view.reanchor(1).unwrap();
+        assert_eq!(reanchored.position(), 1);
+        assert_eq!(reanchored.read_byte().unwrap(), 2);
+
+        // Pointing at or past the current position would loop forever if followed.
+        assert!(matches!(view.reanchor(3), Err(ReadError::Other(_))));
+        assert!(matches!(view.reanchor(4), Err(ReadError::Other(_))));
+
+        // Pointing past the end of the buffer is invalid regardless of direction.
+        assert!(matches!(view.reanchor(100), Err(ReadError::Other(_))));
+        let past_end = BorrowView::new(vec![1u8, 2, 3, 4, 5]).skip(10).unwrap();
+        assert!(matches!(past_end.reanchor(7), Err(ReadError::Other(_))));
+    }
+
+    fn temp_file_with(bytes: &[u8]) -> File {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "plasma-view-test-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .read(true)
+            .write(true)
+            .open(&path)
+            .unwrap();
+        file.write_all(bytes).unwrap();
+        file
+    }
+
+    #[test]
+    fn file_view_transcribe_and_skip() {
+        let file = temp_file_with(b"hello world");
+        let view = FileView::new(Rc::new(file));
+        assert_eq!(view.read_byte().unwrap(), b'h');
+        assert_eq!(view.transcribe(5).unwrap().as_slice(), b"hello");
+        let view = view.skip(6).unwrap();
+        assert_eq!(view.transcribe(5).unwrap().as_slice(), b"world");
+    }
+
+    #[test]
+    fn file_view_transcribe_rejects_oversized_request_without_allocating() {
+        // A malformed length header (far larger than the file) must fail fast against the
+        // real file size, not allocate a buffer sized to the bogus length.
+        let file = temp_file_with(b"0123456789");
+        let view = FileView::new(Rc::new(file));
+        assert_eq!(view.transcribe(1 << 40), Err(EndOfStream));
+        assert_eq!(view.hint_available_bytes(), Some(10));
+    }
 }
@@ -0,0 +1,289 @@
+use super::{ReadError, Result, ViewReadError};
+use super::view::View;
+use crate::imports::*;
+use std::convert::{TryFrom, TryInto};
+
+/// A LEB128 varint that did not terminate within 10 bytes, the maximum needed to encode a
+/// `u64`. Reported via [`ReadError::Other`] rather than [`ReadError::EndOfStream`], because
+/// retrying later will not help: the data is simply malformed.
+#[derive(Debug)]
+pub struct VarintTooLong;
+
+impl fmt::Display for VarintTooLong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "varint did not terminate within 10 bytes")
+    }
+}
+
+impl Error for VarintTooLong {}
+impl ViewReadError for VarintTooLong {}
+
+/// A LEB128 varint that decoded correctly, but whose value does not fit in a [`usize`] on
+/// this platform (reachable only where `usize` is narrower than 64 bits, e.g. a 32-bit
+/// target decoding a value above `u32::MAX`, which takes 5 or more bytes). Distinct from
+/// [`VarintTooLong`], which means the encoding itself never terminated.
+#[derive(Debug)]
+pub struct VarintOutOfRange;
+
+impl fmt::Display for VarintOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "varint value does not fit in usize")
+    }
+}
+
+impl Error for VarintOutOfRange {}
+impl ViewReadError for VarintOutOfRange {}
+
+/// The sanity cap `read_frame` enforces on a frame's announced length, so that a malformed
+/// or malicious length header (e.g. `u64::MAX`) cannot trigger a multi-exabyte allocation
+/// attempt before any real data has even arrived. Chosen generously; legitimate frames are
+/// expected to be far smaller.
+const MAX_FRAME_LEN: u64 = 1 << 32; // 4 GiB
+
+/// A frame's announced length header exceeded [`MAX_FRAME_LEN`].
+#[derive(Debug)]
+pub struct FrameTooLarge;
+
+impl fmt::Display for FrameTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "frame length exceeds the maximum allowed size")
+    }
+}
+
+impl Error for FrameTooLarge {}
+impl ViewReadError for FrameTooLarge {}
+
+/// Typed, endianness-aware primitive reads layered on top of [`View`].
+///
+/// `View` only offers `read_byte`/`transcribe`/`skip`, which means every call site that
+/// wants e.g. a little-endian `u32` has to `transcribe(4)`, convert the bytes by hand, and
+/// then `skip(4)`. `ViewExt` does that bookkeeping once. Since views are forward-only and
+/// cheap to clone, each method takes `self` by value and returns the view advanced past the
+/// bytes it consumed, alongside the decoded value.
+pub trait ViewExt: View {
+    /// Read a fixed number of bytes into an array, advancing past them.
+    fn read_array<const N: usize>(self) -> Result<([u8; N], Self)> {
+        let bytes = self.transcribe(N)?;
+        let array: [u8; N] = bytes
+            .as_slice()
+            .try_into()
+            .expect("transcribe(N) returns exactly N bytes");
+        let rest = self.skip(N as u64)?;
+        Ok((array, rest))
+    }
+
+    fn read_u16_le(self) -> Result<(u16, Self)> {
+        let (bytes, rest) = self.read_array::<2>()?;
+        Ok((u16::from_le_bytes(bytes), rest))
+    }
+    fn read_u16_be(self) -> Result<(u16, Self)> {
+        let (bytes, rest) = self.read_array::<2>()?;
+        Ok((u16::from_be_bytes(bytes), rest))
+    }
+    fn read_u32_le(self) -> Result<(u32, Self)> {
+        let (bytes, rest) = self.read_array::<4>()?;
+        Ok((u32::from_le_bytes(bytes), rest))
+    }
+    fn read_u32_be(self) -> Result<(u32, Self)> {
+        let (bytes, rest) = self.read_array::<4>()?;
+        Ok((u32::from_be_bytes(bytes), rest))
+    }
+    fn read_u64_le(self) -> Result<(u64, Self)> {
+        let (bytes, rest) = self.read_array::<8>()?;
+        Ok((u64::from_le_bytes(bytes), rest))
+    }
+    fn read_u64_be(self) -> Result<(u64, Self)> {
+        let (bytes, rest) = self.read_array::<8>()?;
+        Ok((u64::from_be_bytes(bytes), rest))
+    }
+
+    fn read_i16_le(self) -> Result<(i16, Self)> {
+        let (bytes, rest) = self.read_array::<2>()?;
+        Ok((i16::from_le_bytes(bytes), rest))
+    }
+    fn read_i16_be(self) -> Result<(i16, Self)> {
+        let (bytes, rest) = self.read_array::<2>()?;
+        Ok((i16::from_be_bytes(bytes), rest))
+    }
+    fn read_i32_le(self) -> Result<(i32, Self)> {
+        let (bytes, rest) = self.read_array::<4>()?;
+        Ok((i32::from_le_bytes(bytes), rest))
+    }
+    fn read_i32_be(self) -> Result<(i32, Self)> {
+        let (bytes, rest) = self.read_array::<4>()?;
+        Ok((i32::from_be_bytes(bytes), rest))
+    }
+    fn read_i64_le(self) -> Result<(i64, Self)> {
+        let (bytes, rest) = self.read_array::<8>()?;
+        Ok((i64::from_le_bytes(bytes), rest))
+    }
+    fn read_i64_be(self) -> Result<(i64, Self)> {
+        let (bytes, rest) = self.read_array::<8>()?;
+        Ok((i64::from_be_bytes(bytes), rest))
+    }
+
+    /// Read an unsigned LEB128 varint: the low 7 bits of each byte, least-significant group
+    /// first, with the high bit set on every byte but the last.
+    ///
+    /// Errors with [`ReadError::Other`]`(`[`VarintTooLong`]`)` if more than 10 bytes would be
+    /// needed (the maximum for a `u64`). If the stream ends before the terminating byte, the
+    /// underlying `EndOfStream` propagates so async callers can retry once more data arrives.
+    fn read_var_u64(self) -> Result<(u64, Self)> {
+        let mut view = self;
+        let mut result: u64 = 0;
+        let mut n = 0u32;
+        loop {
+            if n >= 10 {
+                return Err(ReadError::Other(Box::new(VarintTooLong)));
+            }
+            let byte = view.read_byte()?;
+            result |= u64::from(byte & 0x7f) << (7 * n);
+            view = view.skip(1)?;
+            n += 1;
+            if byte & 0x80 == 0 {
+                return Ok((result, view));
+            }
+        }
+    }
+
+    /// Read an unsigned LEB128 varint as a [`usize`].
+    fn read_var_usize(self) -> Result<(usize, Self)> {
+        let (value, rest) = self.read_var_u64()?;
+        let value = usize::try_from(value).map_err(|_| ReadError::Other(Box::new(VarintOutOfRange)))?;
+        Ok((value, rest))
+    }
+
+    /// Read a zigzag-encoded signed LEB128 varint.
+    fn read_var_i64(self) -> Result<(i64, Self)> {
+        let (value, rest) = self.read_var_u64()?;
+        let decoded = (value >> 1) as i64 ^ -((value & 1) as i64);
+        Ok((decoded, rest))
+    }
+
+    /// Read a length-prefixed frame: an 8-byte big-endian `u64` length header followed by
+    /// that many payload bytes.
+    ///
+    /// Returns `(payload, rest)`, where `payload` is bound to exactly the announced length
+    /// and `rest` is the view skipped past the header and the payload. Because reading the
+    /// header can return `EndOfStream` before the full frame has arrived, callers streaming
+    /// from the network should simply retry once more data is available.
+    ///
+    /// The announced length is checked against [`MAX_FRAME_LEN`] before binding a payload
+    /// view to it — otherwise a malformed or malicious header (e.g. a stray `u64::MAX`)
+    /// could bind, and later allocate for, a payload far larger than anything that could
+    /// legitimately arrive. This is a sanity cap, not an availability check: whether the
+    /// announced bytes have actually arrived yet is still determined by `skip`/`transcribe`
+    /// on the returned views, which may legitimately return `EndOfStream` for a retry.
+    fn read_frame(self) -> Result<(Self, Self)> {
+        let (len, after_header) = self.read_u64_be()?;
+        if len > MAX_FRAME_LEN {
+            return Err(ReadError::Other(Box::new(FrameTooLarge)));
+        }
+        let payload = after_header.clone().bound(len);
+        let rest = after_header.skip(len)?;
+        Ok((payload, rest))
+    }
+}
+
+impl<V: View> ViewExt for V {}
+
+#[cfg(test)]
+mod test {
+    use super::super::view::BorrowView;
+    use super::super::ReadError;
+    use super::*;
+
+    #[test]
+    fn read_u32_be_and_le_roundtrip() {
+        let view = BorrowView::new(vec![0x01, 0x02, 0x03, 0x04]);
+        let (be, _) = view.clone().read_u32_be().unwrap();
+        assert_eq!(be, 0x01020304);
+        let (le, _) = view.read_u32_le().unwrap();
+        assert_eq!(le, 0x04030201);
+    }
+
+    #[test]
+    fn read_var_u64_decodes_multi_byte_value() {
+        // 300 = 0b1_0010_1100, LEB128-encoded as [0xAC, 0x02].
+        let view = BorrowView::new(vec![0xAC, 0x02]);
+        let (value, rest) = view.read_var_u64().unwrap();
+        assert_eq!(value, 300);
+        assert!(rest.transcribe(1).is_err());
+    }
+
+    #[test]
+    fn read_var_u64_propagates_end_of_stream_when_truncated() {
+        // Continuation bit set, but no following byte: the caller should be able to retry.
+        let view = BorrowView::new(vec![0x80]);
+        assert!(matches!(view.read_var_u64(), Err(ReadError::EndOfStream)));
+    }
+
+    #[test]
+    fn read_var_u64_errors_past_ten_continuation_bytes() {
+        let view = BorrowView::new(vec![0xFF; 11]);
+        assert!(matches!(view.read_var_u64(), Err(ReadError::Other(_))));
+    }
+
+    #[test]
+    fn read_var_i64_zigzag_roundtrip() {
+        // zigzag(-1) = 1, zigzag(1) = 2, both single-byte varints.
+        let (negative_one, _) = BorrowView::new(vec![0x01]).read_var_i64().unwrap();
+        assert_eq!(negative_one, -1);
+        let (one, _) = BorrowView::new(vec![0x02]).read_var_i64().unwrap();
+        assert_eq!(one, 1);
+    }
+
+    #[test]
+    fn varint_out_of_range_has_a_distinct_message_from_too_long() {
+        // The two varint failure modes (malformed encoding vs. out-of-range value) must not
+        // share a `Display` message, or a decoded-but-oversized value would misreport as a
+        // non-terminating encoding.
+        assert_ne!(VarintOutOfRange.to_string(), VarintTooLong.to_string());
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn read_var_usize_reports_out_of_range_distinctly() {
+        // One past u32::MAX: decodes fine as a u64, but cannot fit in a 32-bit usize.
+        let bytes: Vec<u8> = vec![0x80, 0x80, 0x80, 0x80, 0x10];
+        let view = BorrowView::new(bytes);
+        let err = view.read_var_usize().err().expect("expected an out-of-range error");
+        match err {
+            ReadError::Other(err) => assert_eq!(err.to_string(), VarintOutOfRange.to_string()),
+            ReadError::EndOfStream => panic!("expected VarintOutOfRange, got EndOfStream"),
+        }
+    }
+
+    #[test]
+    fn read_frame_bounds_payload_to_announced_length() {
+        let mut bytes = vec![0u8; 8];
+        bytes[7] = 5; // length header = 5
+        bytes.extend_from_slice(b"helloXXX");
+        let view = BorrowView::new(bytes);
+        let (payload, rest) = view.read_frame().unwrap();
+        assert_eq!(payload.transcribe(5).unwrap().as_slice(), b"hello");
+        assert_eq!(rest.transcribe(3).unwrap().as_slice(), b"XXX");
+    }
+
+    #[test]
+    fn read_frame_with_not_yet_arrived_payload_still_reports_end_of_stream() {
+        // The announced length is within the sane cap, but fewer bytes are actually present
+        // (as for a frame still streaming in over the network). `read_frame` itself should
+        // still succeed — it only binds the payload view — and reading from that view is
+        // what must stay a retryable `EndOfStream`, not a hard `FrameTooLarge` sanity error.
+        let mut bytes = vec![0u8; 8];
+        bytes[7] = 5; // length header = 5
+        bytes.extend_from_slice(b"ab"); // only 2 bytes have arrived so far
+        let view = BorrowView::new(bytes);
+        let (payload, _rest) = view.read_frame().unwrap();
+        assert!(matches!(payload.transcribe(5), Err(ReadError::EndOfStream)));
+    }
+
+    #[test]
+    fn read_frame_rejects_length_exceeding_max_frame_len() {
+        let mut bytes = vec![0xFFu8; 8]; // length header = u64::MAX
+        bytes.extend_from_slice(b"hello");
+        let view = BorrowView::new(bytes);
+        assert!(matches!(view.read_frame(), Err(ReadError::Other(_))));
+    }
+}
@@ -0,0 +1,239 @@
+use super::view::{SmallVecU8, View};
+use super::{ReadError, Result, ViewReadError};
+use crate::imports::*;
+use smallvec::SmallVec;
+use std::cmp::min;
+use std::io;
+use std::io::{Read, Seek, SeekFrom};
+
+/// A view with neither a known `bound_len` nor a `hint_available_bytes`, so its total length
+/// is unknown, was asked to drain to a `Vec`.
+#[derive(Debug)]
+pub struct UnboundedView;
+
+impl fmt::Display for UnboundedView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot drain a view with no known bound_len or available-bytes hint")
+    }
+}
+
+impl Error for UnboundedView {}
+impl ViewReadError for UnboundedView {}
+
+/// Adapts a [`View`] to [`std::io::Read`] and [`std::io::Seek`] (seeking from the end needs
+/// a known `bound_len`), so plasma views interoperate with the wider ecosystem built on `Read`.
+///
+/// `View` is forward-only, so seeking backward beyond the current position is not supported.
+pub struct ViewReader<V: View> {
+    view: V,
+    position: u64,
+}
+
+impl<V: View> ViewReader<V> {
+    pub fn new(view: V) -> Self {
+        ViewReader { view, position: 0 }
+    }
+
+    /// Drain a bounded view into a `Vec<u8>` in one go, pre-reserving capacity from
+    /// `bound_len` (or, when that is `None`, `hint_available_bytes`) the way the `SizeHint`-driven builders in `tokio-buf` do, and the way
+    /// `std`'s own `read_to_end` sizes its buffer.
+    pub fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        let len = self
+            .view
+            .bound_len()
+            .or_else(|| self.view.hint_available_bytes())
+            .ok_or_else(|| ReadError::Other(Box::new(UnboundedView)))?;
+        let bytes = self.view.transcribe(len)?;
+        self.view = self.view.clone().skip(len as u64)?;
+        self.position += len as u64;
+        let mut vec = Vec::with_capacity(bytes.len());
+        vec.extend_from_slice(&bytes);
+        Ok(vec)
+    }
+
+    fn to_io_error(err: ReadError) -> io::Error {
+        let kind = match err {
+            ReadError::EndOfStream => io::ErrorKind::UnexpectedEof,
+            ReadError::Other(_) => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, err.to_string())
+    }
+}
+
+impl<V: View> Read for ViewReader<V> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let want = match self.view.hint_available_bytes() {
+            Some(0) => return Ok(0),
+            Some(available) => min(buf.len(), available),
+            None => buf.len(),
+        };
+        let bytes = match transcribe_prefix(&self.view, want) {
+            Ok(bytes) => bytes,
+            Err(other) => return Err(Self::to_io_error(other)),
+        };
+        self.view = self
+            .view
+            .clone()
+            .skip(bytes.len() as u64)
+            .map_err(Self::to_io_error)?;
+        self.position += bytes.len() as u64;
+        buf[..bytes.len()].copy_from_slice(&bytes);
+        Ok(bytes.len())
+    }
+}
+
+/// `View::transcribe` is all-or-nothing: a request for more bytes than are currently
+/// available fails outright with `EndOfStream`, rather than returning what it can (the way
+/// `Read::read` is allowed to do a short read). When `hint_available_bytes` doesn't already
+/// tell us how much there is, find the longest readable prefix of `max_len` bytes by binary
+/// search, so a too-large request still yields the real trailing bytes instead of losing
+/// them and reporting a premature EOF.
+fn transcribe_prefix<V: View>(view: &V, max_len: usize) -> Result<SmallVecU8> {
+    if max_len == 0 {
+        return Ok(SmallVec::new());
+    }
+    match view.transcribe(max_len) {
+        Ok(bytes) => Ok(bytes),
+        Err(ReadError::EndOfStream) => {
+            let mut good = 0; // known to succeed (not re-verified: `transcribe(0)` can itself
+                              // fail once the view is already past its end, e.g. `BorrowView`)
+            let mut bad = max_len; // known to fail
+            while good + 1 < bad {
+                let mid = good + (bad - good) / 2;
+                match view.transcribe(mid) {
+                    Ok(_) => good = mid,
+                    Err(ReadError::EndOfStream) => bad = mid,
+                    Err(other) => return Err(other),
+                }
+            }
+            if good == 0 {
+                Ok(SmallVec::new())
+            } else {
+                view.transcribe(good)
+            }
+        }
+        Err(other) => Err(other),
+    }
+}
+
+impl<V: View> Seek for ViewReader<V> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(n) => n,
+            SeekFrom::Current(delta) => offset_by(self.position, delta)?,
+            SeekFrom::End(delta) => {
+                let len = self.view.bound_len().ok_or_else(|| {
+                    io::Error::other("seek from end requires a known bound_len")
+                })?;
+                offset_by(self.position + len as u64, delta)?
+            }
+        };
+        if target < self.position {
+            return Err(io::Error::other(
+                "ViewReader is forward-only and cannot seek backward",
+            ));
+        }
+        self.view = self
+            .view
+            .clone()
+            .skip(target - self.position)
+            .map_err(Self::to_io_error)?;
+        self.position = target;
+        Ok(self.position)
+    }
+}
+
+fn offset_by(base: u64, delta: i64) -> io::Result<u64> {
+    if delta >= 0 {
+        base.checked_add(delta as u64)
+    } else {
+        base.checked_sub((-delta) as u64)
+    }
+    .ok_or_else(|| io::Error::other("seek offset out of range"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::view::BorrowView;
+    use super::*;
+    use std::io::{Read, Seek, SeekFrom};
+
+    /// A view that never reports `hint_available_bytes` (the `View` trait's default),
+    /// wrapping a `BorrowView` so `ViewReader::read` can't size its request from a hint and
+    /// must fall back to `transcribe_prefix`'s binary search on a too-large request.
+    #[derive(Clone)]
+    struct NoHintView(BorrowView<Vec<u8>>);
+
+    impl View for NoHintView {
+        fn read_byte(&self) -> Result<u8> {
+            self.0.read_byte()
+        }
+        fn transcribe(&self, len: usize) -> Result<SmallVecU8> {
+            self.0.transcribe(len)
+        }
+        fn skip(self, bytes: u64) -> Result<Self> {
+            Ok(NoHintView(self.0.skip(bytes)?))
+        }
+        fn bound(self, len: u64) -> Self {
+            NoHintView(self.0.bound(len))
+        }
+        fn bound_len(&self) -> Option<usize> {
+            self.0.bound_len()
+        }
+    }
+
+    #[test]
+    fn read_fills_buffer_across_multiple_calls() {
+        let view = BorrowView::new(b"hello world".to_vec());
+        let mut reader = ViewReader::new(view);
+        let mut buf = [0u8; 5];
+        assert_eq!(reader.read(&mut buf).unwrap(), 5);
+        assert_eq!(&buf, b"hello");
+        assert_eq!(reader.read(&mut buf).unwrap(), 5);
+        assert_eq!(&buf, b" worl");
+        let mut tail = [0u8; 5];
+        assert_eq!(reader.read(&mut tail).unwrap(), 1);
+        assert_eq!(&tail[..1], b"d");
+        assert_eq!(reader.read(&mut tail).unwrap(), 0);
+    }
+
+    #[test]
+    fn read_does_a_real_short_read_instead_of_reporting_false_eof() {
+        // Regression test: when `hint_available_bytes` is unavailable (the `View` trait's
+        // default, as `FileView` had before it gained an override), requesting more than is
+        // available used to make `transcribe` fail all-or-nothing, which `read` mapped
+        // straight to `Ok(0)` — silently losing every trailing byte instead of returning
+        // them via a real short read.
+        let view = NoHintView(BorrowView::new(b"0123456789".to_vec()));
+        let mut reader = ViewReader::new(view);
+        let mut buf = [0u8; 64];
+        let n = reader.read(&mut buf).unwrap();
+        assert_eq!(n, 10);
+        assert_eq!(&buf[..10], b"0123456789");
+        // The stream is now genuinely exhausted.
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn read_to_end_collects_all_bytes_of_a_bounded_view() {
+        let view = BorrowView::new(b"hello world".to_vec());
+        let mut reader = ViewReader::new(view);
+        assert_eq!(reader.read_to_end().unwrap(), b"hello world");
+    }
+
+    #[test]
+    fn seek_moves_forward_but_rejects_going_backward() {
+        let view = BorrowView::new(b"0123456789".to_vec());
+        let mut reader = ViewReader::new(view);
+        assert_eq!(reader.seek(SeekFrom::Start(3)).unwrap(), 3);
+        let mut buf = [0u8; 2];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"34");
+        assert_eq!(reader.seek(SeekFrom::Current(2)).unwrap(), 7);
+        assert_eq!(reader.seek(SeekFrom::End(0)).unwrap(), 10);
+        assert!(reader.seek(SeekFrom::Start(0)).is_err());
+    }
+}